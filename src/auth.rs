@@ -0,0 +1,58 @@
+use std::fmt::{self, Debug, Formatter};
+
+/// A secret value (bearer token, password) that redacts itself in `Debug` output
+///
+/// Used by [`crate::AlertmanagerClientBuilder`] so credentials never end up in logs that
+/// `Debug`-print a client or its configuration.
+#[derive(Clone)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Wrap a secret value
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Access the underlying value
+    ///
+    /// Named `expose_secret` rather than a plain getter so call sites make it obvious they're
+    /// handling sensitive data.
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(\"[redacted]\")")
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_debug_is_redacted() {
+        let secret = Secret::new("super-secret-token");
+        assert_eq!(format!("{secret:?}"), "Secret(\"[redacted]\")");
+    }
+
+    #[test]
+    fn test_secret_exposes_value() {
+        let secret = Secret::new("super-secret-token");
+        assert_eq!(secret.expose_secret(), "super-secret-token");
+    }
+}
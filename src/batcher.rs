@@ -0,0 +1,403 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+use crate::client::AlertmanagerClient;
+use crate::types::Alert;
+
+/// Configuration for [`AlertBatcher`]
+#[derive(Debug, Clone)]
+pub struct BatchConfig {
+    /// Flush as soon as the buffer reaches this many alerts
+    pub max_batch_size: usize,
+    /// Flush on this interval even if `max_batch_size` hasn't been reached
+    pub flush_interval: Duration,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 100,
+            flush_interval: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Number of attempts `shutdown` makes at the final flush before returning the undelivered alerts
+const SHUTDOWN_FLUSH_ATTEMPTS: u32 = 3;
+
+/// Cloneable handle for enqueuing alerts onto a running [`AlertBatcher`]
+///
+/// Enqueuing never blocks on the network; alerts are buffered in memory until the batcher
+/// flushes them via `push_alerts`.
+#[derive(Clone)]
+pub struct AlertSender {
+    tx: mpsc::UnboundedSender<Alert>,
+}
+
+impl AlertSender {
+    /// Queue an alert for the next flush
+    ///
+    /// Silently drops the alert if the batcher has already shut down.
+    pub fn enqueue(&self, alert: Alert) {
+        let _ = self.tx.send(alert);
+    }
+}
+
+/// Background task that coalesces alerts and flushes them to Alertmanager on an interval
+///
+/// Built on top of [`AlertmanagerClient`] for high-frequency sources where pushing one HTTP
+/// request per alert would be wasteful.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use alert_manager_api::{AlertBatcher, AlertmanagerClient, Alert, BatchConfig};
+/// use url::Url;
+/// use std::time::Duration;
+///
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = AlertmanagerClient::new(Url::parse("http://localhost:9093")?, Duration::from_secs(10))?;
+/// let (sender, batcher) = AlertBatcher::spawn(client, BatchConfig::default());
+///
+/// sender.enqueue(Alert::new("HighMemoryUsage"));
+///
+/// if let Err(undelivered) = batcher.shutdown().await {
+///     eprintln!("{} alerts could not be delivered before shutdown", undelivered.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct AlertBatcher {
+    shutdown: Option<oneshot::Sender<()>>,
+    handle: Option<JoinHandle<()>>,
+    leftover: Option<oneshot::Receiver<Vec<Alert>>>,
+}
+
+impl AlertBatcher {
+    /// Start the background flush task, returning a cloneable sender and the batcher handle
+    pub fn spawn(client: AlertmanagerClient, config: BatchConfig) -> (AlertSender, Self) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let (shutdown_tx, shutdown_rx) = oneshot::channel();
+        let (leftover_tx, leftover_rx) = oneshot::channel();
+
+        let handle = tokio::spawn(run(client, config, rx, shutdown_rx, leftover_tx));
+
+        (
+            AlertSender { tx },
+            Self {
+                shutdown: Some(shutdown_tx),
+                handle: Some(handle),
+                leftover: Some(leftover_rx),
+            },
+        )
+    }
+
+    /// Flush any remaining buffered alerts and wait for the background task to finish
+    ///
+    /// The final flush is attempted up to [`SHUTDOWN_FLUSH_ATTEMPTS`] times in total while it
+    /// keeps failing, whatever the error. If alerts are still undelivered after that, they are returned as `Err`
+    /// instead of being silently dropped, so callers can log, persist, or re-enqueue them
+    /// elsewhere.
+    pub async fn shutdown(mut self) -> Result<(), Vec<Alert>> {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.await;
+        }
+
+        let leftover = match self.leftover.take() {
+            Some(rx) => rx.await.unwrap_or_default(),
+            None => Vec::new(),
+        };
+
+        if leftover.is_empty() {
+            Ok(())
+        } else {
+            Err(leftover)
+        }
+    }
+}
+
+async fn run(
+    client: AlertmanagerClient,
+    config: BatchConfig,
+    mut rx: mpsc::UnboundedReceiver<Alert>,
+    mut shutdown_rx: oneshot::Receiver<()>,
+    leftover_tx: oneshot::Sender<Vec<Alert>>,
+) {
+    let mut buffer: Vec<Alert> = Vec::new();
+    let mut interval = tokio::time::interval(config.flush_interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    interval.tick().await; // first tick fires immediately; skip it
+    let mut rx_closed = false;
+
+    let leftover = loop {
+        tokio::select! {
+            received = rx.recv(), if !rx_closed => {
+                match received {
+                    Some(alert) => {
+                        buffer.push(alert);
+                        if buffer.len() >= config.max_batch_size {
+                            flush(&client, &mut buffer).await;
+                        }
+                    }
+                    // All senders dropped: keep flushing on ticks until `shutdown`
+                    None => rx_closed = true,
+                }
+            }
+            _ = interval.tick() => {
+                flush(&client, &mut buffer).await;
+            }
+            _ = &mut shutdown_rx => {
+                while let Ok(alert) = rx.try_recv() {
+                    buffer.push(alert);
+                }
+
+                // Retry the final flush a few times before accepting the loss; a transient
+                // failure (e.g. a 503) at the moment of shutdown is plausible and shouldn't
+                // silently drop the whole last batch.
+                for _ in 0..SHUTDOWN_FLUSH_ATTEMPTS {
+                    flush(&client, &mut buffer).await;
+                    if buffer.is_empty() {
+                        break;
+                    }
+                }
+
+                if !buffer.is_empty() {
+                    warn!(
+                        remaining = buffer.len(),
+                        "Giving up on final flush during shutdown; returning undelivered alerts to caller"
+                    );
+                }
+
+                break buffer;
+            }
+        }
+    };
+
+    let _ = leftover_tx.send(leftover);
+}
+
+async fn flush(client: &AlertmanagerClient, buffer: &mut Vec<Alert>) {
+    if buffer.is_empty() {
+        return;
+    }
+
+    let deduped = dedup_keep_latest(std::mem::take(buffer));
+
+    match client.push_alerts(deduped.clone()).await {
+        Ok(()) => {}
+        Err(err) if err.is_retryable() => {
+            warn!(error = %err, "Batch flush failed, re-queuing for next tick");
+            *buffer = deduped;
+        }
+        Err(err) => {
+            warn!(
+                error = %err,
+                "Batch flush failed with non-retryable error, re-queuing so the alerts are \
+                 still surfaced as undelivered on shutdown rather than silently dropped"
+            );
+            *buffer = deduped;
+        }
+    }
+}
+
+/// Deduplicate alerts by their stable label fingerprint, keeping the most recent version
+fn dedup_keep_latest(alerts: Vec<Alert>) -> Vec<Alert> {
+    let mut deduped: HashMap<u64, Alert> = HashMap::new();
+    for alert in alerts {
+        deduped.insert(alert.dedup_key(), alert);
+    }
+    deduped.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use url::Url;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_batcher_flushes_on_size_threshold() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let (sender, batcher) = AlertBatcher::spawn(
+            client,
+            BatchConfig {
+                max_batch_size: 2,
+                flush_interval: Duration::from_secs(60),
+            },
+        );
+
+        sender.enqueue(Alert::new("Alert1"));
+        sender.enqueue(Alert::new("Alert2"));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+
+        assert!(batcher.shutdown().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_batcher_flushes_on_interval() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let (sender, batcher) = AlertBatcher::spawn(
+            client,
+            BatchConfig {
+                max_batch_size: 100,
+                flush_interval: Duration::from_millis(20),
+            },
+        );
+
+        sender.enqueue(Alert::new("Alert1"));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+
+        assert!(batcher.shutdown().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_batcher_shutdown_flushes_remaining_alerts() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let (sender, batcher) = AlertBatcher::spawn(
+            client,
+            BatchConfig {
+                max_batch_size: 100,
+                flush_interval: Duration::from_secs(60),
+            },
+        );
+
+        sender.enqueue(Alert::new("Alert1"));
+        assert!(batcher.shutdown().await.is_ok());
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_batcher_shutdown_returns_undelivered_alerts_on_persistent_failure() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("Service unavailable"))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let (sender, batcher) = AlertBatcher::spawn(
+            client,
+            BatchConfig {
+                max_batch_size: 100,
+                flush_interval: Duration::from_secs(60),
+            },
+        );
+
+        sender.enqueue(Alert::new("Alert1"));
+
+        let result = batcher.shutdown().await;
+        let undelivered = result.expect_err("persistent 503s should surface undelivered alerts");
+        assert_eq!(undelivered.len(), 1);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), SHUTDOWN_FLUSH_ATTEMPTS as usize);
+    }
+
+    #[tokio::test]
+    async fn test_batcher_shutdown_returns_undelivered_alerts_on_non_retryable_failure() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(ResponseTemplate::new(400).set_body_string("Bad request"))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let (sender, batcher) = AlertBatcher::spawn(
+            client,
+            BatchConfig {
+                max_batch_size: 100,
+                flush_interval: Duration::from_secs(60),
+            },
+        );
+
+        sender.enqueue(Alert::new("Alert1"));
+
+        let result = batcher.shutdown().await;
+        let undelivered = result.expect_err("a non-retryable error should still surface undelivered alerts");
+        assert_eq!(undelivered.len(), 1);
+    }
+
+    #[test]
+    fn test_dedup_keep_latest_prefers_last_occurrence() {
+        let firing = Alert::new("HighCpu").with_label("service", "api");
+        let resolved = Alert::new("HighCpu")
+            .with_label("service", "api")
+            .resolve();
+
+        let deduped = dedup_keep_latest(vec![firing, resolved.clone()]);
+
+        assert_eq!(deduped.len(), 1);
+        assert_eq!(deduped[0].ends_at, resolved.ends_at);
+    }
+}
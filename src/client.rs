@@ -1,11 +1,125 @@
-use reqwest::Client;
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use base64::Engine;
+use http::Extensions;
+use rand::Rng;
+use reqwest::{Client, Request};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, Middleware, Next};
 use std::time::Duration;
-use tracing::{debug, instrument};
+use tracing::{debug, instrument, warn};
 use url::Url;
 
+use crate::auth::Secret;
 use crate::errors::{AlertmanagerError, Result};
-use crate::types::Alert;
+use crate::types::{Alert, AlertSeverity};
+
+/// Configuration for the retry middleware installed by [`AlertmanagerClient::with_retries`]
+///
+/// Retries use exponential backoff with full jitter: on attempt `n` the client sleeps
+/// `min(max_delay, base_delay * 2^n)` multiplied by a random factor in `[0.5, 1.0]`.
+#[derive(Debug, Clone)]
+pub struct RetryConfig {
+    /// Maximum number of attempts (including the first) before giving up
+    pub max_attempts: u32,
+    /// Base delay used for the exponential backoff calculation
+    pub base_delay: Duration,
+    /// Upper bound on the computed backoff delay
+    pub max_delay: Duration,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+/// `reqwest_middleware` layer that retries requests classified as retryable
+/// (network/timeout errors and HTTP 5xx) using exponential backoff with full jitter
+struct RetryMiddleware {
+    config: RetryConfig,
+    /// Overall budget for the request, including all retries and backoff sleeps
+    timeout: Duration,
+}
+
+impl RetryMiddleware {
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self.config.base_delay.saturating_mul(1u32 << attempt.min(31));
+        let capped = std::cmp::min(exp, self.config.max_delay);
+        let jitter = rand::thread_rng().gen_range(0.5..=1.0);
+        capped.mul_f64(jitter)
+    }
+}
+
+fn is_retryable_transport_error(err: &reqwest_middleware::Error) -> bool {
+    match err {
+        reqwest_middleware::Error::Reqwest(source) => source.is_connect() || source.is_timeout(),
+        reqwest_middleware::Error::Middleware(_) => false,
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RetryMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        let started_at = std::time::Instant::now();
+        let mut attempt = 0u32;
+
+        loop {
+            let elapsed = started_at.elapsed();
+            if elapsed >= self.timeout {
+                return Err(reqwest_middleware::Error::Middleware(anyhow::anyhow!(
+                    "exceeded overall timeout of {:?} while retrying",
+                    self.timeout
+                )));
+            }
+            let remaining = self.timeout - elapsed;
+
+            let attempt_req = req.try_clone().ok_or_else(|| {
+                reqwest_middleware::Error::Middleware(anyhow::anyhow!(
+                    "request body cannot be cloned for retry"
+                ))
+            })?;
+
+            // Bound this attempt by the remaining overall budget rather than reqwest's
+            // per-request `Client` timeout, which resets on every retry and would let a
+            // string of slow attempts blow well past `self.timeout` in aggregate.
+            let result = match tokio::time::timeout(
+                remaining,
+                next.clone().run(attempt_req, extensions),
+            )
+            .await
+            {
+                Ok(result) => result,
+                Err(_) => {
+                    return Err(reqwest_middleware::Error::Middleware(anyhow::anyhow!(
+                        "exceeded overall timeout of {:?} while retrying",
+                        self.timeout
+                    )));
+                }
+            };
+            attempt += 1;
+
+            let should_retry = match &result {
+                Ok(response) => response.status().as_u16() >= 500,
+                Err(err) => is_retryable_transport_error(err),
+            };
+
+            if !should_retry || attempt >= self.config.max_attempts {
+                return result;
+            }
+
+            let delay = self.backoff(attempt);
+            warn!(attempt, ?delay, "Retrying Alertmanager request");
+            tokio::time::sleep(delay.min(self.timeout.saturating_sub(started_at.elapsed()))).await;
+        }
+    }
+}
 
 /// Client for pushing alerts to Alertmanager
 ///
@@ -59,6 +173,33 @@ impl AlertmanagerClient {
         Ok(Self { client, api_url })
     }
 
+    /// Create a new Alertmanager client with automatic retries
+    ///
+    /// Installs a retry middleware that retries requests classified as retryable by
+    /// [`AlertmanagerError::is_retryable`] (network/timeout errors and HTTP 5xx responses)
+    /// using exponential backoff with full jitter, up to `retry_config.max_attempts`.
+    ///
+    /// The overall time spent across all attempts and backoff sleeps is bounded by `timeout`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn with_retries(api_url: Url, timeout: Duration, retry_config: RetryConfig) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(timeout)
+            .build()
+            .map_err(AlertmanagerError::BuildHttpClient)?;
+
+        let client = ClientBuilder::new(client)
+            .with(RetryMiddleware {
+                config: retry_config,
+                timeout,
+            })
+            .build();
+
+        Ok(Self { client, api_url })
+    }
+
     /// Create a new client with a custom reqwest middleware client
     ///
     /// This allows you to add custom middleware (retry, logging, etc.)
@@ -128,13 +269,233 @@ impl AlertmanagerClient {
     pub fn api_url(&self) -> &Url {
         &self.api_url
     }
+
+    /// Access the underlying middleware client
+    ///
+    /// Used by sibling modules (e.g. the query and silence subsystems) that need to issue
+    /// requests beyond `push_alerts`.
+    pub(crate) fn http_client(&self) -> &ClientWithMiddleware {
+        &self.client
+    }
+
+    /// Install a panic hook that pushes `template` as a critical alert when the process panics
+    ///
+    /// The hook clones `template`, attaches the panic message and thread/location as
+    /// annotations plus a `severity=critical` label, and pushes it on a short-lived Tokio
+    /// runtime so the alert has a chance to leave before the process dies. The previously
+    /// installed hook still runs first, so existing panic output (e.g. to stderr) is preserved.
+    ///
+    /// This consumes the client because the installed hook owns it for the remainder of the
+    /// process.
+    pub fn install_panic_handler(self, template: Alert) {
+        let previous_hook = std::panic::take_hook();
+
+        std::panic::set_hook(Box::new(move |info| {
+            previous_hook(info);
+
+            let alert = template
+                .clone()
+                .with_severity(AlertSeverity::Critical)
+                .with_annotation("panic_message", &panic_payload(info))
+                .with_annotation(
+                    "thread",
+                    std::thread::current().name().unwrap_or("unnamed"),
+                )
+                .with_annotation(
+                    "location",
+                    &info
+                        .location()
+                        .map(|l| l.to_string())
+                        .unwrap_or_else(|| "unknown".to_string()),
+                );
+
+            let client = self.clone();
+            let joined = std::thread::spawn(move || {
+                let runtime = match tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                {
+                    Ok(runtime) => runtime,
+                    Err(_) => return,
+                };
+                let _ = runtime.block_on(client.push_alert(alert));
+            })
+            .join();
+
+            if joined.is_err() {
+                eprintln!("alert_manager_api: failed to push panic alert");
+            }
+        }));
+    }
+}
+
+fn panic_payload(info: &std::panic::PanicHookInfo<'_>) -> String {
+    if let Some(message) = info.payload().downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = info.payload().downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Credentials applied as a default `Authorization` header by [`AlertmanagerClientBuilder`]
+#[derive(Clone)]
+enum Credentials {
+    Bearer(Secret),
+    Basic { username: String, password: Secret },
+}
+
+impl Credentials {
+    fn header_value(&self) -> String {
+        match self {
+            Self::Bearer(token) => format!("Bearer {}", token.expose_secret()),
+            Self::Basic { username, password } => {
+                let encoded = base64::engine::general_purpose::STANDARD
+                    .encode(format!("{username}:{}", password.expose_secret()));
+                format!("Basic {encoded}")
+            }
+        }
+    }
+}
+
+/// Builder for an [`AlertmanagerClient`] with authentication, proxy, and header configuration
+///
+/// This removes the need to drop down to [`AlertmanagerClient::with_client`] just to add a
+/// token or route through a proxy.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use alert_manager_api::AlertmanagerClientBuilder;
+/// use url::Url;
+/// use std::time::Duration;
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = AlertmanagerClientBuilder::new(Url::parse("http://localhost:9093")?)
+///     .timeout(Duration::from_secs(10))
+///     .bearer_token("my-token")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct AlertmanagerClientBuilder {
+    api_url: Url,
+    timeout: Duration,
+    credentials: Option<Credentials>,
+    proxy_url: Option<Url>,
+    default_headers: reqwest::header::HeaderMap,
+    retry_config: Option<RetryConfig>,
+}
+
+impl AlertmanagerClientBuilder {
+    /// Start building a client for the given Alertmanager base URL
+    pub fn new(api_url: Url) -> Self {
+        Self {
+            api_url,
+            timeout: Duration::from_secs(10),
+            credentials: None,
+            proxy_url: None,
+            default_headers: reqwest::header::HeaderMap::new(),
+            retry_config: None,
+        }
+    }
+
+    /// Set the request timeout (defaults to 10 seconds)
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Authenticate with a bearer token on every request
+    pub fn bearer_token(mut self, token: impl Into<Secret>) -> Self {
+        self.credentials = Some(Credentials::Bearer(token.into()));
+        self
+    }
+
+    /// Authenticate with HTTP basic auth on every request
+    pub fn basic_auth(mut self, username: impl Into<String>, password: impl Into<Secret>) -> Self {
+        self.credentials = Some(Credentials::Basic {
+            username: username.into(),
+            password: password.into(),
+        });
+        self
+    }
+
+    /// Route outbound requests through an HTTP/SOCKS proxy
+    pub fn proxy(mut self, proxy_url: Url) -> Self {
+        self.proxy_url = Some(proxy_url);
+        self
+    }
+
+    /// Add a custom default header sent on every request
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `name` or `value` are not valid header contents.
+    pub fn default_header(mut self, name: &str, value: &str) -> Result<Self> {
+        let header_name = reqwest::header::HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| AlertmanagerError::ClientConfig(e.to_string()))?;
+        let header_value = reqwest::header::HeaderValue::from_str(value)
+            .map_err(|e| AlertmanagerError::ClientConfig(e.to_string()))?;
+        self.default_headers.insert(header_name, header_value);
+        Ok(self)
+    }
+
+    /// Install the retry middleware described by [`RetryConfig`]
+    pub fn retries(mut self, retry_config: RetryConfig) -> Self {
+        self.retry_config = Some(retry_config);
+        self
+    }
+
+    /// Build the configured client
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client, proxy, or credentials cannot be constructed.
+    pub fn build(self) -> Result<AlertmanagerClient> {
+        let mut headers = self.default_headers;
+
+        if let Some(credentials) = &self.credentials {
+            let header_value = reqwest::header::HeaderValue::from_str(&credentials.header_value())
+                .map_err(|e| AlertmanagerError::ClientConfig(e.to_string()))?;
+            headers.insert(reqwest::header::AUTHORIZATION, header_value);
+        }
+
+        let mut http_builder = Client::builder()
+            .timeout(self.timeout)
+            .default_headers(headers);
+
+        if let Some(proxy_url) = self.proxy_url {
+            let proxy = reqwest::Proxy::all(proxy_url)
+                .map_err(|e| AlertmanagerError::ClientConfig(e.to_string()))?;
+            http_builder = http_builder.proxy(proxy);
+        }
+
+        let http_client = http_builder
+            .build()
+            .map_err(AlertmanagerError::BuildHttpClient)?;
+
+        let mut middleware_builder = ClientBuilder::new(http_client);
+        if let Some(retry_config) = self.retry_config {
+            middleware_builder = middleware_builder.with(RetryMiddleware {
+                config: retry_config,
+                timeout: self.timeout,
+            });
+        }
+
+        Ok(AlertmanagerClient {
+            client: middleware_builder.build(),
+            api_url: self.api_url,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::types::AlertSeverity;
-    use wiremock::matchers::{method, path};
+    use wiremock::matchers::{header, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[tokio::test]
@@ -263,4 +624,174 @@ mod tests {
         let client = AlertmanagerClient::new(url.clone(), Duration::from_secs(10)).unwrap();
         assert_eq!(client.api_url(), &url);
     }
+
+    #[tokio::test]
+    async fn test_with_retries_recovers_after_server_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::with_retries(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+            RetryConfig {
+                max_attempts: 3,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(10),
+            },
+        )
+        .unwrap();
+
+        let alert = Alert::new("TestAlert");
+        let result = client.push_alert(alert).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_with_retries_gives_up_after_max_attempts() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(ResponseTemplate::new(503).set_body_string("Service unavailable"))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::with_retries(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+            RetryConfig {
+                max_attempts: 2,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(10),
+            },
+        )
+        .unwrap();
+
+        let alert = Alert::new("TestAlert");
+        let result = client.push_alert(alert).await;
+        assert!(result.is_err());
+        if let Err(AlertmanagerError::Api { status, .. }) = result {
+            assert_eq!(status, 503);
+        } else {
+            panic!("Expected Api error");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_install_panic_handler_pushes_alert_on_panic() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let template = Alert::new("ProcessPanicked").with_label("service", "test");
+        client.install_panic_handler(template);
+
+        let result = std::panic::catch_unwind(|| {
+            panic!("boom");
+        });
+        assert!(result.is_err());
+
+        // The hook joins its pusher thread before returning; the short sleep is extra headroom.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_builder_bearer_token_sets_authorization_header() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .and(header("authorization", "Bearer my-token"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClientBuilder::new(Url::parse(&mock_server.uri()).unwrap())
+            .bearer_token("my-token")
+            .build()
+            .unwrap();
+
+        let result = client.push_alert(Alert::new("TestAlert")).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_builder_basic_auth_sets_authorization_header() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .and(header(
+                "authorization",
+                format!(
+                    "Basic {}",
+                    base64::engine::general_purpose::STANDARD.encode("user:pass")
+                )
+                .as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClientBuilder::new(Url::parse(&mock_server.uri()).unwrap())
+            .basic_auth("user", "pass")
+            .build()
+            .unwrap();
+
+        let result = client.push_alert(Alert::new("TestAlert")).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_builder_default_header_is_sent() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/alerts"))
+            .and(header("x-team", "sre"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClientBuilder::new(Url::parse(&mock_server.uri()).unwrap())
+            .default_header("x-team", "sre")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let result = client.push_alert(Alert::new("TestAlert")).await;
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_header_value() {
+        let result = AlertmanagerClientBuilder::new(Url::parse("http://localhost:9093").unwrap())
+            .default_header("x-team", "bad\nvalue");
+        assert!(result.is_err());
+    }
 }
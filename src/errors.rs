@@ -27,6 +27,14 @@ pub enum AlertmanagerError {
         /// Error message from Alertmanager
         message: String,
     },
+
+    /// Failed to decode a response body from Alertmanager
+    #[error("Failed to decode Alertmanager response: {0}")]
+    Decode(#[source] reqwest::Error),
+
+    /// Failed to configure the HTTP client (invalid proxy URL, header value, or credentials)
+    #[error("Failed to configure Alertmanager client: {0}")]
+    ClientConfig(String),
 }
 
 impl AlertmanagerError {
@@ -35,10 +35,18 @@
 //! }
 //! ```
 
+mod auth;
+mod batcher;
 mod client;
 mod errors;
+mod query;
+mod silence;
 mod types;
 
-pub use client::AlertmanagerClient;
+pub use auth::Secret;
+pub use batcher::{AlertBatcher, AlertSender, BatchConfig};
+pub use client::{AlertmanagerClient, AlertmanagerClientBuilder, RetryConfig};
 pub use errors::{AlertmanagerError, Result};
-pub use types::{Alert, AlertSeverity};
+pub use query::AlertQuery;
+pub use silence::{ActiveSilence, Matcher, Silence, SilenceQuery, SilenceStatus};
+pub use types::{ActiveAlert, Alert, AlertGroup, AlertSeverity, AlertStatus, Receiver};
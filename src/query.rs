@@ -0,0 +1,312 @@
+use async_stream::try_stream;
+use futures::Stream;
+use serde::de::DeserializeOwned;
+use url::Url;
+
+use crate::client::AlertmanagerClient;
+use crate::errors::{AlertmanagerError, Result};
+use crate::types::{ActiveAlert, AlertGroup};
+
+/// HTTP header Alertmanager sets on list responses to point at the next page
+///
+/// When absent, the current page is the last one.
+const NEXT_CURSOR_HEADER: &str = "x-next-cursor";
+
+/// Builder for filtering `GET /api/v2/alerts` and `GET /api/v2/alerts/groups` queries
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use alert_manager_api::AlertQuery;
+///
+/// let query = AlertQuery::new()
+///     .filter("service", "api-server")
+///     .active(true)
+///     .silenced(false);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AlertQuery {
+    filters: Vec<String>,
+    active: Option<bool>,
+    silenced: Option<bool>,
+    inhibited: Option<bool>,
+    unprocessed: Option<bool>,
+}
+
+impl AlertQuery {
+    /// Create an empty query that matches every alert
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict results to alerts with the label `name` set to `value`
+    ///
+    /// Can be called multiple times to add several `filter=label=value` constraints.
+    pub fn filter(mut self, name: &str, value: &str) -> Self {
+        self.filters.push(format!("{name}={value}"));
+        self
+    }
+
+    /// Include or exclude active alerts
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Include or exclude silenced alerts
+    pub fn silenced(mut self, silenced: bool) -> Self {
+        self.silenced = Some(silenced);
+        self
+    }
+
+    /// Include or exclude inhibited alerts
+    pub fn inhibited(mut self, inhibited: bool) -> Self {
+        self.inhibited = Some(inhibited);
+        self
+    }
+
+    /// Include or exclude unprocessed alerts
+    pub fn unprocessed(mut self, unprocessed: bool) -> Self {
+        self.unprocessed = Some(unprocessed);
+        self
+    }
+
+    fn apply_to(&self, url: &mut Url) {
+        let mut pairs = url.query_pairs_mut();
+        for filter in &self.filters {
+            pairs.append_pair("filter", filter);
+        }
+        if let Some(active) = self.active {
+            pairs.append_pair("active", &active.to_string());
+        }
+        if let Some(silenced) = self.silenced {
+            pairs.append_pair("silenced", &silenced.to_string());
+        }
+        if let Some(inhibited) = self.inhibited {
+            pairs.append_pair("inhibited", &inhibited.to_string());
+        }
+        if let Some(unprocessed) = self.unprocessed {
+            pairs.append_pair("unprocessed", &unprocessed.to_string());
+        }
+    }
+}
+
+impl AlertmanagerClient {
+    /// List currently known alerts, following pagination lazily
+    ///
+    /// Returns a [`Stream`] so large result sets can be iterated without buffering everything
+    /// into memory; use [`futures::StreamExt`] to consume it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use alert_manager_api::{AlertmanagerClient, AlertQuery};
+    /// # use futures::StreamExt;
+    /// # async fn example(client: AlertmanagerClient) {
+    /// let alerts = client.alerts(AlertQuery::new().active(true));
+    /// futures::pin_mut!(alerts);
+    /// while let Some(alert) = alerts.next().await {
+    ///     let alert = alert.expect("request failed");
+    ///     println!("{}", alert.fingerprint);
+    /// }
+    /// # }
+    /// ```
+    pub fn alerts(&self, query: AlertQuery) -> impl Stream<Item = Result<ActiveAlert>> + '_ {
+        self.paginate("/api/v2/alerts", query)
+    }
+
+    /// List alert groups, following pagination lazily
+    ///
+    /// Returns a [`Stream`] so large result sets can be iterated without buffering everything
+    /// into memory; use [`futures::StreamExt`] to consume it.
+    pub fn alert_groups(&self, query: AlertQuery) -> impl Stream<Item = Result<AlertGroup>> + '_ {
+        self.paginate("/api/v2/alerts/groups", query)
+    }
+
+    fn paginate<'a, T>(
+        &'a self,
+        path: &'static str,
+        query: AlertQuery,
+    ) -> impl Stream<Item = Result<T>> + 'a
+    where
+        T: DeserializeOwned + 'a,
+    {
+        try_stream! {
+            let mut cursor: Option<String> = None;
+
+            loop {
+                let (items, next_cursor) = self.fetch_page::<T>(path, &query, cursor.as_deref()).await?;
+
+                for item in items {
+                    yield item;
+                }
+
+                match next_cursor {
+                    Some(next) => cursor = Some(next),
+                    None => break,
+                }
+            }
+        }
+    }
+
+    async fn fetch_page<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &AlertQuery,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<T>, Option<String>)> {
+        let mut url = self.api_url().join(path).expect("Valid URL path");
+        query.apply_to(&mut url);
+        if let Some(cursor) = cursor {
+            url.query_pairs_mut().append_pair("cursor", cursor);
+        }
+
+        let response = self
+            .http_client()
+            .get(url)
+            .send()
+            .await
+            .map_err(AlertmanagerError::Request)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(AlertmanagerError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        let next_cursor = response
+            .headers()
+            .get(NEXT_CURSOR_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        let items = response
+            .json::<Vec<T>>()
+            .await
+            .map_err(AlertmanagerError::Decode)?;
+
+        Ok((items, next_cursor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::AlertmanagerClient;
+    use futures::StreamExt;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_alerts_stream_collects_single_page() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "fingerprint": "abc123",
+                    "status": {"state": "active", "silencedBy": [], "inhibitedBy": []},
+                    "receivers": [{"name": "default"}],
+                    "labels": {"alertname": "TestAlert"},
+                    "annotations": {},
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let alerts: Vec<_> = client
+            .alerts(AlertQuery::new())
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].fingerprint, "abc123");
+    }
+
+    #[tokio::test]
+    async fn test_alerts_stream_follows_cursor_pagination() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v2/alerts"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("x-next-cursor", "page2")
+                    .set_body_json(serde_json::json!([
+                        {
+                            "fingerprint": "first",
+                            "status": {"state": "active", "silencedBy": [], "inhibitedBy": []},
+                            "receivers": [],
+                            "labels": {},
+                            "annotations": {},
+                        }
+                    ])),
+            )
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v2/alerts"))
+            .and(query_param("cursor", "page2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "fingerprint": "second",
+                    "status": {"state": "active", "silencedBy": [], "inhibitedBy": []},
+                    "receivers": [],
+                    "labels": {},
+                    "annotations": {},
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let fingerprints: Vec<String> = client
+            .alerts(AlertQuery::new())
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+            .into_iter()
+            .map(|alert| alert.fingerprint)
+            .collect();
+
+        assert_eq!(fingerprints, vec!["first".to_string(), "second".to_string()]);
+    }
+
+    #[test]
+    fn test_query_builder_encodes_filters() {
+        let mut url = Url::parse("http://localhost:9093/api/v2/alerts").unwrap();
+        let query = AlertQuery::new()
+            .filter("service", "api-server")
+            .active(true)
+            .silenced(false);
+
+        query.apply_to(&mut url);
+
+        assert!(url.query().unwrap().contains("filter=service%3Dapi-server"));
+        assert!(url.query().unwrap().contains("active=true"));
+        assert!(url.query().unwrap().contains("silenced=false"));
+    }
+}
@@ -0,0 +1,386 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::client::AlertmanagerClient;
+use crate::errors::{AlertmanagerError, Result};
+
+/// A matcher used to select which alerts a [`Silence`] applies to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Matcher {
+    /// Label name to match
+    pub name: String,
+    /// Label value (or regular expression, if `is_regex` is set) to match
+    pub value: String,
+    /// Whether `value` is a regular expression
+    #[serde(rename = "isRegex")]
+    pub is_regex: bool,
+    /// Whether the match is an equality check (`false` negates it)
+    #[serde(rename = "isEqual")]
+    pub is_equal: bool,
+}
+
+/// Builder for a silence submitted to `POST /api/v2/silences`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use alert_manager_api::Silence;
+/// use chrono::{Duration, Utc};
+///
+/// let silence = Silence::new("oncall-bot", "Scheduled deploy", Utc::now() + Duration::hours(1))
+///     .with_matcher("service", "api-server")
+///     .with_regex_matcher("env", "staging|prod");
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Silence {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    matchers: Vec<Matcher>,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    created_by: String,
+    comment: String,
+}
+
+impl Silence {
+    /// Create a new silence lasting until `ends_at`
+    ///
+    /// The silence starts immediately; use [`Silence::with_starts_at`] to change that.
+    pub fn new(created_by: &str, comment: &str, ends_at: DateTime<Utc>) -> Self {
+        Self {
+            id: None,
+            matchers: Vec::new(),
+            starts_at: Utc::now(),
+            ends_at,
+            created_by: created_by.to_string(),
+            comment: comment.to_string(),
+        }
+    }
+
+    /// Add an equality matcher on a label
+    pub fn with_matcher(mut self, name: &str, value: &str) -> Self {
+        self.matchers.push(Matcher {
+            name: name.to_string(),
+            value: value.to_string(),
+            is_regex: false,
+            is_equal: true,
+        });
+        self
+    }
+
+    /// Add a regular-expression matcher on a label
+    pub fn with_regex_matcher(mut self, name: &str, value: &str) -> Self {
+        self.matchers.push(Matcher {
+            name: name.to_string(),
+            value: value.to_string(),
+            is_regex: true,
+            is_equal: true,
+        });
+        self
+    }
+
+    /// Set a custom start time (defaults to now)
+    pub fn with_starts_at(mut self, time: DateTime<Utc>) -> Self {
+        self.starts_at = time;
+        self
+    }
+}
+
+/// A silence as returned by Alertmanager's silence API
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveSilence {
+    /// Silence ID
+    pub id: String,
+    /// Matchers selecting which alerts this silence applies to
+    pub matchers: Vec<Matcher>,
+    /// Start time of the silence
+    pub starts_at: DateTime<Utc>,
+    /// End time of the silence
+    pub ends_at: DateTime<Utc>,
+    /// Who created the silence
+    pub created_by: String,
+    /// Free-text reason for the silence
+    pub comment: String,
+    /// Current status of the silence
+    pub status: SilenceStatus,
+}
+
+/// Processing status of an [`ActiveSilence`]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SilenceStatus {
+    /// Alertmanager's computed state: `"pending"`, `"active"`, or `"expired"`
+    pub state: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateSilenceResponse {
+    #[serde(rename = "silenceID")]
+    silence_id: String,
+}
+
+/// Builder for filtering `GET /api/v2/silences` queries
+///
+/// Mirrors [`crate::AlertQuery`]'s `filter=label=value` convention.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use alert_manager_api::SilenceQuery;
+///
+/// let query = SilenceQuery::new().filter("service", "api-server");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SilenceQuery {
+    filters: Vec<String>,
+}
+
+impl SilenceQuery {
+    /// Create an empty query that matches every silence
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restrict results to silences with the label `name` set to `value`
+    ///
+    /// Can be called multiple times to add several `filter=label=value` constraints.
+    pub fn filter(mut self, name: &str, value: &str) -> Self {
+        self.filters.push(format!("{name}={value}"));
+        self
+    }
+
+    fn apply_to(&self, url: &mut Url) {
+        let mut pairs = url.query_pairs_mut();
+        for filter in &self.filters {
+            pairs.append_pair("filter", filter);
+        }
+    }
+}
+
+/// Build the `/api/v2/silence/{id}` URL, percent-encoding `id` as a single opaque path segment
+///
+/// `id` is caller-supplied and not guaranteed to be the shape Alertmanager itself returns from
+/// `create_silence`, so it must not be spliced into the path as raw text (e.g. a `"../"` or
+/// `"?"` in `id` could otherwise redirect the request to a different endpoint).
+fn silence_url(api_url: &Url, id: &str) -> Url {
+    let mut url = api_url.join("/api/v2/silence/").expect("Valid URL path");
+    url.path_segments_mut()
+        .expect("Valid base URL")
+        .pop_if_empty()
+        .push(id);
+    url
+}
+
+impl AlertmanagerClient {
+    /// Create a silence, returning its ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails or Alertmanager returns a non-success status.
+    pub async fn create_silence(&self, silence: Silence) -> Result<String> {
+        let url = self
+            .api_url()
+            .join("/api/v2/silences")
+            .expect("Valid URL path");
+
+        let response = self
+            .http_client()
+            .post(url)
+            .json(&silence)
+            .send()
+            .await
+            .map_err(AlertmanagerError::Request)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(AlertmanagerError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        let body = response
+            .json::<CreateSilenceResponse>()
+            .await
+            .map_err(AlertmanagerError::Decode)?;
+
+        Ok(body.silence_id)
+    }
+
+    /// List silences, optionally restricted by a [`SilenceQuery`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails or Alertmanager returns a non-success status.
+    pub async fn list_silences(&self, query: SilenceQuery) -> Result<Vec<ActiveSilence>> {
+        let mut url = self
+            .api_url()
+            .join("/api/v2/silences")
+            .expect("Valid URL path");
+
+        query.apply_to(&mut url);
+
+        let response = self
+            .http_client()
+            .get(url)
+            .send()
+            .await
+            .map_err(AlertmanagerError::Request)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(AlertmanagerError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        response
+            .json::<Vec<ActiveSilence>>()
+            .await
+            .map_err(AlertmanagerError::Decode)
+    }
+
+    /// Expire a silence by ID
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails or Alertmanager returns a non-success status.
+    pub async fn expire_silence(&self, id: &str) -> Result<()> {
+        let url = silence_url(self.api_url(), id);
+
+        let response = self
+            .http_client()
+            .delete(url)
+            .send()
+            .await
+            .map_err(AlertmanagerError::Request)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let message = response.text().await.unwrap_or_default();
+            return Err(AlertmanagerError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::AlertmanagerClient;
+    use std::time::Duration;
+    use url::Url;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_create_silence_returns_id() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v2/silences"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"silenceID": "abc-123"})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let silence = Silence::new("oncall-bot", "Scheduled deploy", Utc::now())
+            .with_matcher("service", "api-server");
+
+        let id = client.create_silence(silence).await.unwrap();
+        assert_eq!(id, "abc-123");
+    }
+
+    #[tokio::test]
+    async fn test_list_silences() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v2/silences"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+                {
+                    "id": "abc-123",
+                    "matchers": [{"name": "service", "value": "api-server", "isRegex": false, "isEqual": true}],
+                    "startsAt": "2026-01-01T00:00:00Z",
+                    "endsAt": "2026-01-01T01:00:00Z",
+                    "createdBy": "oncall-bot",
+                    "comment": "Scheduled deploy",
+                    "status": {"state": "active"},
+                }
+            ])))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let silences = client.list_silences(SilenceQuery::new()).await.unwrap();
+        assert_eq!(silences.len(), 1);
+        assert_eq!(silences[0].id, "abc-123");
+    }
+
+    #[test]
+    fn test_silence_query_builder_encodes_filters() {
+        let mut url = Url::parse("http://localhost:9093/api/v2/silences").unwrap();
+        let query = SilenceQuery::new().filter("service", "api-server");
+
+        query.apply_to(&mut url);
+
+        assert!(url.query().unwrap().contains("filter=service%3Dapi-server"));
+    }
+
+    #[tokio::test]
+    async fn test_expire_silence() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("DELETE"))
+            .and(path("/api/v2/silence/abc-123"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&mock_server)
+            .await;
+
+        let client = AlertmanagerClient::new(
+            Url::parse(&mock_server.uri()).unwrap(),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+
+        let result = client.expire_silence("abc-123").await;
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_silence_url_percent_encodes_path_traversal() {
+        let api_url = Url::parse("http://localhost:9093").unwrap();
+
+        // A crafted id containing path traversal or query-string syntax must stay confined to
+        // its own path segment rather than redirecting the request elsewhere.
+        let url = silence_url(&api_url, "../../v2/alerts");
+        assert_eq!(url.path(), "/api/v2/silence/..%2F..%2Fv2%2Falerts");
+
+        let url = silence_url(&api_url, "abc-123?x=1&y=2");
+        assert_eq!(url.path(), "/api/v2/silence/abc-123%3Fx=1&y=2");
+        assert!(url.query().is_none());
+    }
+}
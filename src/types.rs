@@ -2,6 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
+use std::hash::{DefaultHasher, Hash, Hasher};
 
 /// Alert severity levels
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -152,6 +153,96 @@ impl Alert {
     pub fn alertname(&self) -> Option<&str> {
         self.labels.get("alertname").map(|s| s.as_str())
     }
+
+    /// Compute a stable fingerprint from the alert's labels
+    ///
+    /// Mirrors how Alertmanager groups alerts: identical labels always produce the same
+    /// fingerprint, regardless of `HashMap` iteration order. Useful for local deduplication
+    /// and as a grouping handle before alerts are pushed.
+    pub fn dedup_key(&self) -> u64 {
+        let mut pairs: Vec<(&String, &String)> = self.labels.iter().collect();
+        pairs.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut hasher = DefaultHasher::new();
+        for (key, value) in pairs {
+            key.hash(&mut hasher);
+            value.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
+
+/// An alert as returned by Alertmanager's query API (`GET /api/v2/alerts`)
+///
+/// Unlike [`Alert`], which is the payload sent when pushing, this captures the
+/// server-computed view of an alert, including its fingerprint and current status.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveAlert {
+    /// Stable identifier Alertmanager assigns to the alert
+    pub fingerprint: String,
+
+    /// Current processing status (active/suppressed, silenced by, inhibited by)
+    pub status: AlertStatus,
+
+    /// Receivers this alert is routed to
+    #[serde(default)]
+    pub receivers: Vec<Receiver>,
+
+    /// Start time of the alert
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starts_at: Option<DateTime<Utc>>,
+
+    /// End time (if resolved)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ends_at: Option<DateTime<Utc>>,
+
+    /// Labels identifying the alert
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+
+    /// Annotations attached to the alert
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
+
+    /// Generator URL (link back to source)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generator_url: Option<String>,
+}
+
+/// Processing status of an [`ActiveAlert`]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertStatus {
+    /// Alertmanager's computed state: `"active"`, `"suppressed"`, or `"unprocessed"`
+    pub state: String,
+
+    /// IDs of silences currently suppressing this alert
+    #[serde(default)]
+    pub silenced_by: Vec<String>,
+
+    /// IDs of alerts currently inhibiting this alert
+    #[serde(default)]
+    pub inhibited_by: Vec<String>,
+}
+
+/// A receiver an [`ActiveAlert`] is routed to
+#[derive(Debug, Clone, Deserialize)]
+pub struct Receiver {
+    /// Receiver name as configured in Alertmanager's routing tree
+    pub name: String,
+}
+
+/// A group of alerts sharing the same grouping labels (`GET /api/v2/alerts/groups`)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertGroup {
+    /// Labels the alerts in this group are grouped by
+    pub labels: HashMap<String, String>,
+
+    /// Alerts belonging to this group
+    #[serde(default)]
+    pub alerts: Vec<ActiveAlert>,
 }
 
 impl Default for Alert {
@@ -219,6 +310,26 @@ mod tests {
         assert_eq!(alert.alertname(), Some("MyAlert"));
     }
 
+    #[test]
+    fn test_dedup_key_is_order_independent() {
+        let a = Alert::new("TestAlert")
+            .with_label("service", "api")
+            .with_label("env", "prod");
+        let b = Alert::new("TestAlert")
+            .with_label("env", "prod")
+            .with_label("service", "api");
+
+        assert_eq!(a.dedup_key(), b.dedup_key());
+    }
+
+    #[test]
+    fn test_dedup_key_differs_for_different_labels() {
+        let a = Alert::new("TestAlert").with_label("service", "api");
+        let b = Alert::new("TestAlert").with_label("service", "worker");
+
+        assert_ne!(a.dedup_key(), b.dedup_key());
+    }
+
     #[test]
     fn test_alert_with_all_fields() {
         let now = Utc::now();